@@ -1,8 +1,11 @@
+use pyo3::exceptions::{PyValueError, PyRuntimeError};
 use pyo3::prelude::*;
 use pyo3::types::{PyDict, PyList};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
 use std::thread;
-use std::time::{Duration, Instant};
-use crossbeam_channel::{bounded, Receiver, Sender, TrySendError};
+use std::time::Duration;
+use flume::{bounded, Receiver, Selector, Sender, SendTimeoutError, TrySendError};
 
 struct LogItem {
     collection: Py<PyAny>,
@@ -11,37 +14,124 @@ struct LogItem {
     vector: Option<Vec<f32>>,
 }
 
+/// Flush counters shared between every worker and the manager, tallied over the
+/// pool's lifetime and reported back by `shutdown`.
+#[derive(Clone)]
+struct FlushStats {
+    items_flushed: Arc<AtomicU64>,
+    batches_flushed: Arc<AtomicU64>,
+    callback_failures: Arc<AtomicU64>,
+}
+
+/// One wakeup of the worker's `Selector`.
+enum WorkerEvent {
+    Item(LogItem),
+    Tick,
+    Stop,
+}
+
+/// What `add_object` does when the bounded queue is full.
+enum OverflowPolicy {
+    /// Drop the item and bump `dropped_count` (the historical fire-and-forget behavior).
+    Drop,
+    /// Block the calling (Python) thread until space frees up, propagating backpressure.
+    Block,
+    /// Block for at most the given duration, then raise a Python exception.
+    BlockWithTimeout(Duration),
+}
+
+impl OverflowPolicy {
+    /// Parse the policy from the Python-facing string plus an optional timeout.
+    ///
+    /// `"drop"` and `"block"` ignore `timeout_ms`; `"block_with_timeout"` requires it.
+    fn from_args(name: &str, timeout_ms: Option<u64>) -> PyResult<Self> {
+        match name {
+            "drop" => Ok(OverflowPolicy::Drop),
+            "block" => Ok(OverflowPolicy::Block),
+            "block_with_timeout" => {
+                let ms = timeout_ms.ok_or_else(|| {
+                    PyValueError::new_err("'block_with_timeout' requires overflow_timeout_ms")
+                })?;
+                Ok(OverflowPolicy::BlockWithTimeout(Duration::from_millis(ms)))
+            }
+            other => Err(PyValueError::new_err(format!(
+                "unknown overflow_policy '{}', expected 'drop', 'block' or 'block_with_timeout'",
+                other
+            ))),
+        }
+    }
+}
+
 #[pyclass]
 struct RustBatchManager {
     sender: Sender<LogItem>,
     flush_callback: Py<PyAny>,
-    worker_handle: Option<thread::JoinHandle<()>>,
+    worker_handles: Vec<thread::JoinHandle<()>>,
     stop_signal: Sender<()>,
+    num_workers: usize,
+    overflow_policy: OverflowPolicy,
+    dropped_count: Arc<AtomicU64>,
+    enqueued_count: Arc<AtomicU64>,
+    items_flushed: Arc<AtomicU64>,
+    batches_flushed: Arc<AtomicU64>,
+    callback_failures: Arc<AtomicU64>,
 }
 
 #[pymethods]
 impl RustBatchManager {
     #[new]
+    #[pyo3(signature = (callback, batch_threshold, flush_interval_ms, num_workers = 1, overflow_policy = "drop", overflow_timeout_ms = None))]
     fn new(
         callback: Py<PyAny>,
         batch_threshold: usize,
         flush_interval_ms: u64,
-    ) -> Self {
+        num_workers: usize,
+        overflow_policy: &str,
+        overflow_timeout_ms: Option<u64>,
+    ) -> PyResult<Self> {
+        if num_workers == 0 {
+            return Err(PyValueError::new_err("num_workers must be at least 1"));
+        }
+        let overflow_policy = OverflowPolicy::from_args(overflow_policy, overflow_timeout_ms)?;
+
         let (tx, rx) = bounded::<LogItem>(10000);
-        let (stop_tx, stop_rx) = bounded(1);
+        // One stop token per worker; each worker consumes exactly one on shutdown.
+        let (stop_tx, stop_rx) = bounded(num_workers);
 
-        let worker_callback = callback.clone();
+        // flume receivers are MPMC: every worker shares clones of the same
+        // `rx`/`stop_rx`, and each queued item is delivered to exactly one worker.
+        let items_flushed = Arc::new(AtomicU64::new(0));
+        let batches_flushed = Arc::new(AtomicU64::new(0));
+        let callback_failures = Arc::new(AtomicU64::new(0));
 
-        let handle = thread::spawn(move || {
-            Self::worker_loop(rx, stop_rx, worker_callback, batch_threshold, flush_interval_ms);
-        });
+        let mut worker_handles = Vec::with_capacity(num_workers);
+        for _ in 0..num_workers {
+            let rx = rx.clone();
+            let stop_rx = stop_rx.clone();
+            let worker_callback = callback.clone();
+            let stats = FlushStats {
+                items_flushed: Arc::clone(&items_flushed),
+                batches_flushed: Arc::clone(&batches_flushed),
+                callback_failures: Arc::clone(&callback_failures),
+            };
+            worker_handles.push(thread::spawn(move || {
+                Self::worker_loop(rx, stop_rx, worker_callback, batch_threshold, flush_interval_ms, stats);
+            }));
+        }
 
-        RustBatchManager {
+        Ok(RustBatchManager {
             sender: tx,
             flush_callback: callback,
-            worker_handle: Some(handle),
+            worker_handles,
             stop_signal: stop_tx,
-        }
+            num_workers,
+            overflow_policy,
+            dropped_count: Arc::new(AtomicU64::new(0)),
+            enqueued_count: Arc::new(AtomicU64::new(0)),
+            items_flushed,
+            batches_flushed,
+            callback_failures,
+        })
     }
 
     fn add_object(
@@ -50,7 +140,7 @@ impl RustBatchManager {
         properties: Py<PyDict>,
         uuid: Option<Py<PyAny>>,
         vector: Option<Vec<f32>>,
-    ) {
+    ) -> PyResult<()> {
         let item = LogItem {
             collection,
             properties,
@@ -58,58 +148,201 @@ impl RustBatchManager {
             vector,
         };
 
-        match self.sender.try_send(item) {
-            Ok(_) => {},
-            Err(TrySendError::Full(_)) => {
-                eprintln!("[RustCore] 🚨 Queue Full! Dropping log item.");
+        match &self.overflow_policy {
+            OverflowPolicy::Drop => match self.sender.try_send(item) {
+                Ok(_) => {
+                    self.enqueued_count.fetch_add(1, Ordering::Relaxed);
+                }
+                Err(TrySendError::Full(_)) => {
+                    self.dropped_count.fetch_add(1, Ordering::Relaxed);
+                }
+                Err(TrySendError::Disconnected(_)) => {
+                    return Err(PyRuntimeError::new_err(
+                        "[RustCore] channel disconnected; worker has stopped",
+                    ));
+                }
+            },
+            OverflowPolicy::Block => match self.sender.send(item) {
+                Ok(_) => {
+                    self.enqueued_count.fetch_add(1, Ordering::Relaxed);
+                }
+                Err(_) => {
+                    return Err(PyRuntimeError::new_err(
+                        "[RustCore] channel disconnected; worker has stopped",
+                    ));
+                }
             },
-            Err(TrySendError::Disconnected(_)) => {
-                eprintln!("[RustCore] ❌ Channel disconnected.");
+            OverflowPolicy::BlockWithTimeout(timeout) => {
+                match self.sender.send_timeout(item, *timeout) {
+                    Ok(_) => {
+                        self.enqueued_count.fetch_add(1, Ordering::Relaxed);
+                    }
+                    Err(SendTimeoutError::Timeout(_)) => {
+                        self.dropped_count.fetch_add(1, Ordering::Relaxed);
+                        return Err(PyRuntimeError::new_err(
+                            "[RustCore] timed out waiting for queue space",
+                        ));
+                    }
+                    Err(SendTimeoutError::Disconnected(_)) => {
+                        return Err(PyRuntimeError::new_err(
+                            "[RustCore] channel disconnected; worker has stopped",
+                        ));
+                    }
+                }
             }
         }
+
+        Ok(())
+    }
+
+    /// Number of items dropped (Drop policy) or timed out (BlockWithTimeout policy).
+    #[getter]
+    fn dropped_count(&self) -> u64 {
+        self.dropped_count.load(Ordering::Relaxed)
+    }
+
+    /// Number of items successfully accepted into the queue.
+    #[getter]
+    fn enqueued_count(&self) -> u64 {
+        self.enqueued_count.load(Ordering::Relaxed)
+    }
+
+    /// Asynchronous counterpart to `add_object` for callers running under asyncio.
+    ///
+    /// Returns an awaitable that resolves once the item is accepted into the queue.
+    /// When the queue is full the coroutine suspends (cooperative backpressure)
+    /// instead of blocking the event loop thread or dropping the item.
+    fn add_object_async<'p>(
+        &self,
+        py: Python<'p>,
+        collection: Py<PyAny>,
+        properties: Py<PyDict>,
+        uuid: Option<Py<PyAny>>,
+        vector: Option<Vec<f32>>,
+    ) -> PyResult<&'p PyAny> {
+        let item = LogItem {
+            collection,
+            properties,
+            uuid,
+            vector,
+        };
+        let sender = self.sender.clone();
+        let enqueued_count = Arc::clone(&self.enqueued_count);
+
+        pyo3_asyncio::tokio::future_into_py(py, async move {
+            match sender.send_async(item).await {
+                Ok(_) => {
+                    enqueued_count.fetch_add(1, Ordering::Relaxed);
+                    Ok(())
+                }
+                Err(_) => Err(PyRuntimeError::new_err(
+                    "[RustCore] channel disconnected; worker has stopped",
+                )),
+            }
+        })
     }
 
-    fn shutdown(&self) {
-        let _ = self.stop_signal.send(());
+    /// Stop the workers, join them, and return a summary of the pool's lifetime:
+    /// `items_flushed`, `batches_flushed` and `callback_failures`.
+    ///
+    /// Joining guarantees the final `flush_buffer` has completed before Python
+    /// regains control, so no data is lost on exit.
+    fn shutdown(&mut self, py: Python) -> PyResult<Py<PyDict>> {
+        // Release the GIL while joining so the workers' final flush can acquire it.
+        py.allow_threads(|| self.stop_and_join());
 
+        let summary = PyDict::new(py);
+        summary.set_item("items_flushed", self.items_flushed.load(Ordering::Relaxed))?;
+        summary.set_item("batches_flushed", self.batches_flushed.load(Ordering::Relaxed))?;
+        summary.set_item(
+            "callback_failures",
+            self.callback_failures.load(Ordering::Relaxed),
+        )?;
+        Ok(summary.into())
     }
 }
 
 impl RustBatchManager {
+    /// Signal every worker to stop, then join each handle. Idempotent: the
+    /// handles are taken out so a later `shutdown`/`Drop` is a no-op.
+    fn stop_and_join(&mut self) {
+        if self.worker_handles.is_empty() {
+            return;
+        }
+        for _ in 0..self.num_workers {
+            let _ = self.stop_signal.send(());
+        }
+        for handle in std::mem::take(&mut self.worker_handles) {
+            let _ = handle.join();
+        }
+    }
+
     fn worker_loop(
         rx: Receiver<LogItem>,
         stop_rx: Receiver<()>,
         callback: Py<PyAny>,
         threshold: usize,
         interval_ms: u64,
+        stats: FlushStats,
     ) {
         let mut buffer = Vec::with_capacity(threshold);
-        let mut last_flush = Instant::now();
-        let flush_interval = Duration::from_millis(interval_ms);
+        let ticker = Self::spawn_ticker(Duration::from_millis(interval_ms));
 
         loop {
-            if let Ok(_) = stop_rx.try_recv() {
-                if !buffer.is_empty() {
-                    Self::flush_buffer(&buffer, &callback);
-                }
-                break;
-            }
+            // flume has no `select!` macro, but its `Selector` waits on several
+            // receivers at once, as crossbeam's `select!` does.
+            let event = Selector::new()
+                .recv(&rx, |msg| match msg {
+                    Ok(item) => WorkerEvent::Item(item),
+                    // Sender dropped: treat like a stop.
+                    Err(_) => WorkerEvent::Stop,
+                })
+                .recv(&ticker, |_| WorkerEvent::Tick)
+                .recv(&stop_rx, |_| WorkerEvent::Stop)
+                .wait();
 
-            match rx.recv_timeout(Duration::from_millis(100)) {
-                Ok(item) => buffer.push(item),
-                Err(_) => {}
+            match event {
+                WorkerEvent::Item(item) => {
+                    buffer.push(item);
+                    if buffer.len() >= threshold {
+                        Self::flush_buffer(&buffer, &callback, &stats);
+                        buffer.clear();
+                    }
+                }
+                WorkerEvent::Tick => {
+                    if !buffer.is_empty() {
+                        Self::flush_buffer(&buffer, &callback, &stats);
+                        buffer.clear();
+                    }
+                }
+                WorkerEvent::Stop => {
+                    // Drain everything still queued so shutdown never loses items.
+                    while let Ok(item) = rx.try_recv() {
+                        buffer.push(item);
+                    }
+                    if !buffer.is_empty() {
+                        Self::flush_buffer(&buffer, &callback, &stats);
+                    }
+                    break;
+                }
             }
+        }
+    }
 
-            let time_since_flush = last_flush.elapsed();
-            if buffer.len() >= threshold || (time_since_flush >= flush_interval && !buffer.is_empty()) {
-                Self::flush_buffer(&buffer, &callback);
-                buffer.clear();
-                last_flush = Instant::now();
+    /// A flume-backed replacement for `crossbeam_channel::tick`: emits `()` every
+    /// `interval` until the returned receiver is dropped.
+    fn spawn_ticker(interval: Duration) -> Receiver<()> {
+        let (tx, rx) = bounded::<()>(1);
+        thread::spawn(move || loop {
+            thread::sleep(interval);
+            if tx.send(()).is_err() {
+                break;
             }
-        }
+        });
+        rx
     }
 
-    fn flush_buffer(buffer: &Vec<LogItem>, callback: &Py<PyAny>) {
+    fn flush_buffer(buffer: &Vec<LogItem>, callback: &Py<PyAny>, stats: &FlushStats) {
         Python::with_gil(|py| {
             let py_list = PyList::empty(py);
 
@@ -133,14 +366,30 @@ impl RustBatchManager {
                 let _ = py_list.append(dict);
             }
 
-            if let Err(e) = callback.call1(py, (py_list,)) {
-                eprintln!("[RustCore] ❌ Callback failed: {}", e);
-                e.print_and_set_sys_last_vars(py);
+            match callback.call1(py, (py_list,)) {
+                Ok(_) => {
+                    stats.items_flushed.fetch_add(buffer.len() as u64, Ordering::Relaxed);
+                    stats.batches_flushed.fetch_add(1, Ordering::Relaxed);
+                }
+                Err(e) => {
+                    stats.callback_failures.fetch_add(1, Ordering::Relaxed);
+                    eprintln!("[RustCore] ❌ Callback failed: {}", e);
+                    e.print_and_set_sys_last_vars(py);
+                }
             }
         });
     }
 }
 
+impl Drop for RustBatchManager {
+    fn drop(&mut self) {
+        // Even if the user never called `shutdown`, drain and join so no queued
+        // item is lost when the object is garbage-collected. Release the GIL
+        // (held by the dropping thread) so the workers' final flush can proceed.
+        Python::with_gil(|py| py.allow_threads(|| self.stop_and_join()));
+    }
+}
+
 #[pymodule]
 fn vectorwave_core(_py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<RustBatchManager>()?;